@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use ron::de::from_str;
+use serde_derive::Deserialize;
+
+/// Represents a dictionary of a specific language, containing all localized words.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Dictionary {
+    pub words: HashMap<String, String>,
+
+    /// Keys that resolve to a different variant depending on a CLDR plural category.
+    #[serde(default)]
+    pub plurals: HashMap<String, PluralVariants>,
+
+    /// Hierarchical, typed entries addressed by a dot-separated path, e.g. `menu.file.open`.
+    #[serde(default)]
+    pub values: HashMap<String, Value>,
+}
+
+impl From<&str> for Dictionary {
+    fn from(s: &str) -> Self {
+        from_str(s).unwrap_or_default()
+    }
+}
+
+impl Dictionary {
+    /// Parses a dictionary from `ron` text, returning the parse error instead of
+    /// silently falling back to an empty dictionary.
+    pub fn try_from_ron(s: &str) -> Result<Self, ron::de::Error> {
+        from_str(s)
+    }
+
+    /// Walks the dot-separated `path` (e.g. `menu.file.open`) into nested `Value::Group`s
+    /// and returns the `Value` found at the end of it, if any.
+    pub fn value_at(&self, path: &str) -> Option<&Value> {
+        let mut segments = path.split('.');
+        let mut current = self.values.get(segments.next()?)?;
+
+        for segment in segments {
+            match current {
+                Value::Group(group) => current = group.get(segment)?,
+                _ => return None,
+            }
+        }
+
+        Some(current)
+    }
+}
+
+/// A typed value stored in a dictionary. Groups nest, which lets a dictionary organize
+/// entries hierarchically instead of as a single flat map of strings.
+#[derive(Debug, Clone, Deserialize)]
+pub enum Value {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Group(HashMap<String, Value>),
+}
+
+impl Value {
+    /// Returns the text of this value, if it is a `Value::Text`.
+    pub fn as_text(&self) -> Option<&String> {
+        match self {
+            Value::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of this value, if it is a `Value::Number`.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Value::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean of this value, if it is a `Value::Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// The CLDR plural categories a single translated message may provide a variant for.
+///
+/// Only the categories `plural_category` can actually produce are represented here;
+/// `zero` and `two` are left out until a supported language rule needs them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    One,
+    Few,
+    Many,
+    Other,
+}
+
+/// Holds the per CLDR plural-category variants of a single translated message. A
+/// category is considered absent when its string is empty, and falls back to `other`.
+///
+/// Plain `String` fields (rather than `Option<String>`) are used deliberately: `ron`
+/// only accepts `Some(...)` for `Option` fields unless the document opts in to
+/// `#![enable(implicit_some)]`, which dictionary files in this repo don't.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct PluralVariants {
+    #[serde(default)]
+    pub one: String,
+    #[serde(default)]
+    pub few: String,
+    #[serde(default)]
+    pub many: String,
+    #[serde(default)]
+    pub other: String,
+}
+
+impl PluralVariants {
+    /// Gets the variant for `category`, falling back to `other` if the category itself
+    /// is absent (empty).
+    pub fn get(&self, category: PluralCategory) -> Option<&str> {
+        let variant = match category {
+            PluralCategory::One => &self.one,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => &self.other,
+        };
+
+        if !variant.is_empty() {
+            Some(variant.as_str())
+        } else if !self.other.is_empty() {
+            Some(self.other.as_str())
+        } else {
+            None
+        }
+    }
+}
+
+/// Resolves the CLDR plural category for `count` in `language`, using a minimal
+/// subset of the CLDR plural rules: English-style (`one` for exactly 1, else `other`)
+/// by default, plus the dedicated Russian and Polish rules.
+pub fn plural_category(language: &str, count: i64) -> PluralCategory {
+    let base = language.split(['_', '-']).next().unwrap_or(language);
+    let n_mod_10 = count.rem_euclid(10);
+    let n_mod_100 = count.rem_euclid(100);
+
+    match base {
+        "ru" => {
+            if n_mod_10 == 1 && n_mod_100 != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&n_mod_10) && !(12..=14).contains(&n_mod_100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "pl" => {
+            if count == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&n_mod_10) && !(12..=14).contains(&n_mod_100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        _ => {
+            if count == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+}
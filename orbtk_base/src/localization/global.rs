@@ -0,0 +1,80 @@
+use std::sync::RwLock;
+
+use once_cell::sync::OnceCell;
+
+use super::{Args, Localization};
+
+static GLOBAL: OnceCell<RwLock<Localization>> = OnceCell::new();
+
+/// Installs `localization` as the global instance used by [`tr`] and [`tr_args`], making
+/// it reachable from anywhere without threading a `Localization` through every widget.
+///
+/// # Panics
+///
+/// Panics if a global localization has already been initialized.
+pub fn init_global(localization: Localization) {
+    GLOBAL
+        .set(RwLock::new(localization))
+        .expect("global localization already initialized");
+}
+
+/// Sets the active language on the global localization.
+///
+/// # Panics
+///
+/// Panics if [`init_global`] has not been called yet.
+pub fn set_global_language(language: &str) {
+    global().write().unwrap().set_language(language);
+}
+
+/// Gets the translated text for `key` from the global localization.
+///
+/// # Panics
+///
+/// Panics if [`init_global`] has not been called yet.
+pub fn tr(key: impl Into<String>) -> String {
+    global().read().unwrap().text(key.into())
+}
+
+/// Gets the translated text for `key` from the global localization, interpolating `args`.
+///
+/// # Panics
+///
+/// Panics if [`init_global`] has not been called yet.
+pub fn tr_args(key: impl Into<String>, args: &Args) -> String {
+    global().read().unwrap().text_with_args(key.into(), args)
+}
+
+fn global() -> &'static RwLock<Localization> {
+    GLOBAL
+        .get()
+        .expect("global localization not initialized; call init_global first")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global() {
+        let en_us = r#"
+        Dictionary(
+            words: {
+                "hello": "Hello",
+            }
+        )
+        "#;
+
+        init_global(
+            Localization::create()
+                .language("en_US")
+                .dictionary("en_US", en_us)
+                .build(),
+        );
+
+        assert_eq!(tr("hello"), "Hello".to_string());
+
+        set_global_language("de_DE");
+        assert_eq!(tr("hello"), "hello".to_string());
+    }
+}
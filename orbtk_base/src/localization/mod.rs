@@ -1,13 +1,21 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 
-use dictionary::Dictionary;
+use dictionary::{plural_category, Dictionary, Value};
 
 mod dictionary;
+mod global;
+
+pub use global::{init_global, set_global_language, tr, tr_args};
 
 /// Used to build a new `Localization` and configure language file path and initial language.
 #[derive(Debug, Default, Clone)]
 pub struct LocalizationBuilder {
     language: String,
+    fallback_order: Vec<String>,
     dictionaries: HashMap<String, Dictionary>,
 }
 
@@ -25,15 +33,119 @@ impl LocalizationBuilder {
         self
     }
 
+    /// Adds the default language to the end of the fallback locales (unless it is already
+    /// listed), so it is tried once a key misses in the current language and in any
+    /// fallback locale listed before it. A later call of `fallback_order` replaces it.
+    pub fn default_language(mut self, language: impl Into<String>) -> Self {
+        let language = language.into();
+        if !self.fallback_order.contains(&language) {
+            self.fallback_order.push(language);
+        }
+        self
+    }
+
+    /// Sets the ordered list of locales that are tried, in turn, whenever a key
+    /// cannot be resolved in the current language's dictionary.
+    pub fn fallback_order(mut self, fallback_order: Vec<String>) -> Self {
+        self.fallback_order = fallback_order;
+        self
+    }
+
+    /// Scans `path` for files named like `dictionary_en_US.ron`, parses each as a
+    /// `Dictionary` and registers it under the locale key derived from its filename
+    /// (`en_US` in that example). Lets an application ship or hot-swap translation
+    /// files on disk instead of embedding them at compile time.
+    pub fn load_dir(mut self, path: impl AsRef<Path>) -> Result<Self, LoadDictionaryError> {
+        let dir = path.as_ref();
+        let entries = fs::read_dir(dir).map_err(|source| LoadDictionaryError::Io {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+
+        for entry in entries {
+            let entry = entry.map_err(|source| LoadDictionaryError::Io {
+                path: dir.to_path_buf(),
+                source,
+            })?;
+            let file_path = entry.path();
+
+            if file_path.extension().and_then(|ext| ext.to_str()) != Some("ron") {
+                continue;
+            }
+
+            let locale = match file_path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.strip_prefix("dictionary_"))
+            {
+                Some(locale) => locale.to_string(),
+                None => continue,
+            };
+
+            let content =
+                fs::read_to_string(&file_path).map_err(|source| LoadDictionaryError::Io {
+                    path: file_path.clone(),
+                    source,
+                })?;
+
+            let dictionary = Dictionary::try_from_ron(&content).map_err(|source| {
+                LoadDictionaryError::Parse {
+                    path: file_path.clone(),
+                    source,
+                }
+            })?;
+
+            self.dictionaries.insert(locale, dictionary);
+        }
+
+        Ok(self)
+    }
+
     /// Builds a new ron localization service.
     pub fn build(self) -> Localization {
         Localization {
             language: self.language,
+            fallback_order: self.fallback_order,
             dictionaries: self.dictionaries,
         }
     }
 }
 
+/// Error produced by [`LocalizationBuilder::load_dir`] while loading dictionaries from disk.
+/// Carries the offending file's path so a single bad file can be identified.
+#[derive(Debug)]
+pub enum LoadDictionaryError {
+    /// The directory, or one of its entries, could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// A dictionary file did not contain valid `ron`.
+    Parse {
+        path: PathBuf,
+        source: ron::de::Error,
+    },
+}
+
+impl fmt::Display for LoadDictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadDictionaryError::Io { path, source } => {
+                write!(f, "could not read '{}': {}", path.display(), source)
+            }
+            LoadDictionaryError::Parse { path, source } => {
+                write!(f, "could not parse '{}': {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadDictionaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadDictionaryError::Io { source, .. } => Some(source),
+            LoadDictionaryError::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
 /// `Localization` represents the default implementation of a localization service based on `ron`.
 ///
 /// # Example
@@ -49,6 +161,7 @@ impl LocalizationBuilder {
 #[derive(Debug, Default, Clone)]
 pub struct Localization {
     language: String,
+    fallback_order: Vec<String>,
     dictionaries: HashMap<String, Dictionary>,
 }
 
@@ -69,15 +182,182 @@ impl Localization {
     }
 
     /// Gets the translated text for the given key. If there is no given translation the `key` will be returned as result.
+    ///
+    /// The lookup tries the current language first and then falls through the
+    /// configured fallback locales, in order, before giving up and returning the `key`.
     pub fn text(&self, key: String) -> String {
-        if let Some(dictionary) = self.dictionaries.get(&self.language) {
-            if let Some(word) = dictionary.words.get(&key) {
-                return word.clone();
+        if let Some(word) = self.lookup(&self.language, &key) {
+            return word;
+        }
+
+        for locale in &self.fallback_order {
+            if let Some(word) = self.lookup(locale, &key) {
+                return word;
             }
         }
 
         key
     }
+
+    /// Gets the translated text for the given key and substitutes `{name}` and positional
+    /// `{}` placeholders found inside it with the supplied `args`. Falls back the same way
+    /// `text` does, and leaves unmatched placeholders untouched. Use `{{` and `}}` to emit
+    /// literal braces.
+    pub fn text_with_args(&self, key: String, args: &Args) -> String {
+        interpolate(&self.text(key), args)
+    }
+
+    /// Gets the translated text for `key`, selecting the CLDR plural-category variant
+    /// that matches `count` in the language of the dictionary being searched (the current
+    /// language first, then each fallback locale). Falls back to the `other` variant
+    /// when the matching category has none, and to the `key` when the whole entry is
+    /// missing from the current language and its fallback locales.
+    pub fn text_plural(&self, key: String, count: i64) -> String {
+        if let Some(word) = self.lookup_plural(&self.language, &key, count) {
+            return word;
+        }
+
+        for locale in &self.fallback_order {
+            if let Some(word) = self.lookup_plural(locale, &key, count) {
+                return word;
+            }
+        }
+
+        key
+    }
+
+    /// Gets the text at the dot-separated `path` (e.g. `menu.file.open`), walking nested
+    /// groups of a typed dictionary. Falls back through the configured fallback locales,
+    /// and finally to `path` itself, the same way `text` falls back to its key.
+    pub fn text_path(&self, path: &str) -> String {
+        if let Some(text) = self
+            .lookup_value(&self.language, path)
+            .and_then(Value::as_text)
+        {
+            return text.clone();
+        }
+
+        for locale in &self.fallback_order {
+            if let Some(text) = self.lookup_value(locale, path).and_then(Value::as_text) {
+                return text.clone();
+            }
+        }
+
+        path.to_string()
+    }
+
+    /// Gets the number at the dot-separated `path`, falling back through the configured
+    /// fallback locales. Returns `None` if the path is missing or not a `Value::Number`.
+    pub fn number(&self, path: &str) -> Option<f64> {
+        self.lookup_value(&self.language, path)
+            .and_then(Value::as_number)
+            .or_else(|| {
+                self.fallback_order
+                    .iter()
+                    .find_map(|locale| self.lookup_value(locale, path).and_then(Value::as_number))
+            })
+    }
+
+    /// Gets the boolean at the dot-separated `path`, falling back through the configured
+    /// fallback locales. Returns `None` if the path is missing or not a `Value::Bool`.
+    pub fn boolean(&self, path: &str) -> Option<bool> {
+        self.lookup_value(&self.language, path)
+            .and_then(Value::as_bool)
+            .or_else(|| {
+                self.fallback_order
+                    .iter()
+                    .find_map(|locale| self.lookup_value(locale, path).and_then(Value::as_bool))
+            })
+    }
+
+    fn lookup(&self, language: &str, key: &str) -> Option<String> {
+        self.dictionaries
+            .get(language)
+            .and_then(|dictionary| dictionary.words.get(key))
+            .cloned()
+    }
+
+    fn lookup_value(&self, language: &str, path: &str) -> Option<&Value> {
+        self.dictionaries.get(language)?.value_at(path)
+    }
+
+    fn lookup_plural(&self, language: &str, key: &str, count: i64) -> Option<String> {
+        self.dictionaries.get(language).and_then(|dictionary| {
+            dictionary
+                .plurals
+                .get(key)
+                .and_then(|variants| variants.get(plural_category(language, count)))
+                .map(str::to_string)
+        })
+    }
+}
+
+/// Arguments used to interpolate placeholders found inside a translated string. See
+/// [`Localization::text_with_args`].
+#[derive(Debug, Clone, Copy)]
+pub enum Args<'a> {
+    /// Resolves named placeholders like `{name}` from a map of argument name to value.
+    Named(&'a HashMap<String, String>),
+    /// Resolves positional `{}` placeholders, in order, from a slice of values.
+    Positional(&'a [String]),
+}
+
+fn interpolate(template: &str, args: &Args) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    let mut positional_index = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for next in chars.by_ref() {
+                    if next == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(next);
+                }
+
+                if !closed {
+                    result.push('{');
+                    result.push_str(&name);
+                    continue;
+                }
+
+                let value = match (name.is_empty(), args) {
+                    (true, Args::Positional(values)) => {
+                        let value = values.get(positional_index).cloned();
+                        positional_index += 1;
+                        value
+                    }
+                    (false, Args::Named(values)) => values.get(&name).cloned(),
+                    _ => None,
+                };
+
+                match value {
+                    Some(value) => result.push_str(&value),
+                    None => {
+                        result.push('{');
+                        result.push_str(&name);
+                        result.push('}');
+                    }
+                }
+            }
+            c => result.push(c),
+        }
+    }
+
+    result
 }
 
 // [START] Conversations
@@ -141,4 +421,233 @@ mod tests {
         assert_eq!(localization.text("world".to_string()), "Welt".to_string());
         assert_eq!(localization.text("test".to_string()), "test".to_string());
     }
+
+    #[test]
+    fn test_fallback_order() {
+        let de_de = r#"
+        Dictionary(
+            words: {
+                "hello": "Hallo",
+            }
+        )
+        "#;
+
+        let en_us = r#"
+        Dictionary(
+            words: {
+                "hello": "Hello",
+                "world": "World",
+            }
+        )
+        "#;
+
+        let localization = Localization::create()
+            .language("de_DE")
+            .dictionary("de_DE", de_de)
+            .dictionary("en_US", en_us)
+            .default_language("en_US")
+            .build();
+
+        assert_eq!(localization.text("hello".to_string()), "Hallo".to_string());
+        assert_eq!(localization.text("world".to_string()), "World".to_string());
+        assert_eq!(localization.text("test".to_string()), "test".to_string());
+    }
+
+    #[test]
+    fn test_text_with_named_args() {
+        let en_us = r#"
+        Dictionary(
+            words: {
+                "greeting": "Hello, {name}!",
+                "escaped": "{{name}} is not {name}",
+            }
+        )
+        "#;
+
+        let localization = Localization::create()
+            .language("en_US")
+            .dictionary("en_US", en_us)
+            .build();
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), "Jane".to_string());
+
+        assert_eq!(
+            localization.text_with_args("greeting".to_string(), &Args::Named(&args)),
+            "Hello, Jane!".to_string()
+        );
+        assert_eq!(
+            localization.text_with_args("escaped".to_string(), &Args::Named(&args)),
+            "{name} is not Jane".to_string()
+        );
+        assert_eq!(
+            localization.text_with_args("missing".to_string(), &Args::Named(&HashMap::new())),
+            "missing".to_string()
+        );
+    }
+
+    #[test]
+    fn test_text_with_positional_args() {
+        let en_us = r#"
+        Dictionary(
+            words: {
+                "greeting": "Hello, {}! You have {} messages.",
+            }
+        )
+        "#;
+
+        let localization = Localization::create()
+            .language("en_US")
+            .dictionary("en_US", en_us)
+            .build();
+
+        let values = vec!["Jane".to_string(), "3".to_string()];
+
+        assert_eq!(
+            localization.text_with_args("greeting".to_string(), &Args::Positional(&values)),
+            "Hello, Jane! You have 3 messages.".to_string()
+        );
+    }
+
+    #[test]
+    fn test_text_plural_english() {
+        let en_us = r#"
+        Dictionary(
+            words: {},
+            plurals: {
+                "messages": (
+                    one: "one message",
+                    other: "{} messages",
+                ),
+            }
+        )
+        "#;
+
+        let localization = Localization::create()
+            .language("en_US")
+            .dictionary("en_US", en_us)
+            .build();
+
+        assert_eq!(
+            localization.text_plural("messages".to_string(), 1),
+            "one message".to_string()
+        );
+        assert_eq!(
+            localization.text_plural("messages".to_string(), 5),
+            "{} messages".to_string()
+        );
+        assert_eq!(
+            localization.text_plural("missing".to_string(), 1),
+            "missing".to_string()
+        );
+    }
+
+    #[test]
+    fn test_text_plural_russian() {
+        let ru_ru = r#"
+        Dictionary(
+            words: {},
+            plurals: {
+                "messages": (
+                    one: "{} сообщение",
+                    few: "{} сообщения",
+                    many: "{} сообщений",
+                    other: "{} сообщения",
+                ),
+            }
+        )
+        "#;
+
+        let localization = Localization::create()
+            .language("ru_RU")
+            .dictionary("ru_RU", ru_ru)
+            .build();
+
+        assert_eq!(
+            localization.text_plural("messages".to_string(), 1),
+            "{} сообщение".to_string()
+        );
+        assert_eq!(
+            localization.text_plural("messages".to_string(), 3),
+            "{} сообщения".to_string()
+        );
+        assert_eq!(
+            localization.text_plural("messages".to_string(), 5),
+            "{} сообщений".to_string()
+        );
+        assert_eq!(
+            localization.text_plural("messages".to_string(), 11),
+            "{} сообщений".to_string()
+        );
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir =
+            std::env::temp_dir().join(format!("orbtk_localization_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("dictionary_en_US.ron"),
+            r#"Dictionary(words: { "hello": "Hello" })"#,
+        )
+        .unwrap();
+        fs::write(dir.join("not_a_dictionary.txt"), "ignored").unwrap();
+
+        let localization = Localization::create()
+            .language("en_US")
+            .load_dir(&dir)
+            .unwrap()
+            .build();
+
+        assert_eq!(localization.text("hello".to_string()), "Hello".to_string());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_dir_reports_parse_errors() {
+        let dir = std::env::temp_dir().join(format!(
+            "orbtk_localization_test_bad_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("dictionary_en_US.ron"), "not valid ron").unwrap();
+
+        let result = Localization::create().load_dir(&dir);
+        assert!(matches!(result, Err(LoadDictionaryError::Parse { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let en_us = r#"
+        Dictionary(
+            words: {},
+            values: {
+                "menu": Group({
+                    "file": Group({
+                        "open": Text("Open"),
+                    }),
+                }),
+                "max_width": Number(640.0),
+                "enabled": Bool(true),
+            }
+        )
+        "#;
+
+        let localization = Localization::create()
+            .language("en_US")
+            .dictionary("en_US", en_us)
+            .build();
+
+        assert_eq!(localization.text_path("menu.file.open"), "Open".to_string());
+        assert_eq!(
+            localization.text_path("menu.file.missing"),
+            "menu.file.missing".to_string()
+        );
+        assert_eq!(localization.number("max_width"), Some(640.0));
+        assert_eq!(localization.boolean("enabled"), Some(true));
+        assert_eq!(localization.number("menu.file.open"), None);
+    }
 }